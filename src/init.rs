@@ -0,0 +1,229 @@
+//! Device initialization.
+//!
+//!
+
+use super::{PortIO, Pic, PicAEOI, PortIOWrapper};
+
+use crate::raw::{ICW1Bits, ICW4Bits, OCW2IRLevel};
+
+#[derive(Debug, Copy, Clone)]
+#[repr(u8)]
+/// Available interrupt trigger modes.
+///
+/// Also contains other ICW1 bitflags.
+pub enum InterruptTriggerMode {
+    EdgeTriggered = ICW1Bits::ICW4_NEEDED,
+    /// Level triggered mode is only used with IBM PS/2 computer.
+    ///
+    /// See section 7, page 1 (PDF page 262) from
+    /// <http://classiccomputers.info/down/IBM_PS2/documents/PS2_Hardware_Interface_Technical_Reference_May88.pdf>
+    LevelTriggered = ICW1Bits::LEVEL_TRIGGERED_MODE | ICW1Bits::ICW4_NEEDED,
+}
+
+/// Start PIC initialization.
+///
+/// In cascade mode the master and slave PICs are initialized with four
+/// Initialization Command Words (ICW). In single PIC mode only the master
+/// PIC is initialized, and ICW3 is skipped.
+pub struct PicInit<T: PortIO>(T);
+
+impl <T: PortIO> PicInit<T> {
+    /// Send ICW1 for a cascade of two 8259A chips (master and slave).
+    pub fn send_icw1(mut port_io: T, mode: InterruptTriggerMode) -> ICW2AndICW3<T> {
+        port_io.write(T::MASTER_PIC_COMMAND_PORT, mode as u8);
+        port_io.write(T::SLAVE_PIC_COMMAND_PORT, mode as u8);
+
+        ICW2AndICW3(port_io)
+    }
+
+    /// Send ICW1 for single PIC mode.
+    ///
+    /// Use this instead of [`PicInit::send_icw1`] on systems that only have
+    /// one 8259A chip. ICW3 isn't sent in single mode, so the returned
+    /// [`ICW2Single`] goes straight from ICW2 to ICW4.
+    pub fn send_icw1_single_mode(mut port_io: T, mode: InterruptTriggerMode) -> ICW2Single<T> {
+        port_io.write(T::MASTER_PIC_COMMAND_PORT, mode as u8 | ICW1Bits::SINGLE_MODE);
+
+        ICW2Single(port_io)
+    }
+}
+
+/// Send the second and third Initialization Command Word (ICW).
+pub struct ICW2AndICW3<T: PortIO>(T);
+
+impl <T: PortIO> ICW2AndICW3<T> {
+    /// Send ICW2 and ICW3 using the IBM PC/AT cascade wiring, where the
+    /// slave PIC is connected to the master's IR2 line.
+    ///
+    /// # Panics
+    ///
+    /// * If `offset & 0b0000_0111 != 0`.
+    pub fn send_icw2_and_icw3(self, master_offset: u8, slave_offset: u8) -> ICW4<T> {
+        self.send_icw2_and_icw3_with_cascade(master_offset, slave_offset, OCW2IRLevel::Two)
+    }
+
+    /// Send ICW2 and ICW3, specifying which master IR line the slave PIC
+    /// cascades on.
+    ///
+    /// ICW2 sets interrupt number offset. ICW3 initializes cascade mode.
+    ///
+    /// Use this instead of [`ICW2AndICW3::send_icw2_and_icw3`] on hardware
+    /// that doesn't wire the slave PIC to the master's IR2 line like the
+    /// IBM PC/AT does.
+    ///
+    /// # Panics
+    ///
+    /// * If `offset & 0b0000_0111 != 0`.
+    pub fn send_icw2_and_icw3_with_cascade(
+        mut self,
+        master_offset: u8,
+        slave_offset: u8,
+        slave_irq: OCW2IRLevel,
+    ) -> ICW4<T> {
+        const NOT_USED_BITS_MASK: u8 = 0b0000_0111;
+
+        if master_offset & NOT_USED_BITS_MASK != 0 {
+            panic!("master_offset & {:#08b} != 0", NOT_USED_BITS_MASK);
+        }
+
+        if slave_offset & NOT_USED_BITS_MASK != 0 {
+            panic!("slave_offset & {:#08b} != 0", NOT_USED_BITS_MASK);
+        }
+
+        self.0.write(T::MASTER_PIC_DATA_PORT, master_offset);
+        self.0.write(T::SLAVE_PIC_DATA_PORT, slave_offset);
+
+        // Send ICW3
+
+        // Bitmask with the bit set for the master IR line the slave occupies.
+        self.0.write(T::MASTER_PIC_DATA_PORT, 1 << slave_irq.bits());
+
+        // IRQ line number where slave PIC is connected.
+        self.0.write(T::SLAVE_PIC_DATA_PORT, slave_irq.bits());
+
+        ICW4(self.0)
+    }
+}
+
+/// Send the second Initialization Command Word (ICW) in single PIC mode.
+///
+/// There is no ICW3 in single mode because there is no cascaded slave PIC,
+/// so this goes straight to [`ICW4Single`].
+pub struct ICW2Single<T: PortIO>(T);
+
+impl <T: PortIO> ICW2Single<T> {
+    /// Send ICW2.
+    ///
+    /// Sets interrupt number offset.
+    ///
+    /// # Panics
+    ///
+    /// * If `offset & 0b0000_0111 != 0`.
+    pub fn send_icw2(mut self, offset: u8) -> ICW4Single<T> {
+        const NOT_USED_BITS_MASK: u8 = 0b0000_0111;
+
+        if offset & NOT_USED_BITS_MASK != 0 {
+            panic!("offset & {:#08b} != 0", NOT_USED_BITS_MASK);
+        }
+
+        self.0.write(T::MASTER_PIC_DATA_PORT, offset);
+
+        ICW4Single(self.0)
+    }
+}
+
+
+pub struct ICW4<T: PortIO>(T);
+
+impl <T: PortIO> ICW4<T> {
+    /// Send ICW4 which sets PICs to Automatic End Of Interrupt (AEOI) mode.
+    ///
+    /// Note that some PC hardware doesn't support AEOI mode.
+    /// 
+    /// This is the most efficient PIC mode, because you don't
+    /// send end of interrupt message to PICs after every
+    /// interrupt.
+    pub fn send_icw4_aeoi(mut self) -> PicAEOI<T> {
+        let icw4 = ICW4Bits::ENABLE_8068_MODE | ICW4Bits::AUTOMATIC_END_OF_INTERRUPT;
+        self.0.write(T::MASTER_PIC_DATA_PORT, icw4);
+        self.0.write(T::SLAVE_PIC_DATA_PORT, icw4);
+
+        PicAEOI(PortIOWrapper(self.0))
+    }
+
+    /// Send ICW4 which sets PICs to default End Of Interrupt (EOI) mode.
+    ///
+    /// In this mode you must send a end of interrupt
+    /// message when receiving interrupt from PIC.
+    pub fn send_icw4(mut self) -> Pic<T> {
+        let icw4 = ICW4Bits::ENABLE_8068_MODE;
+        self.0.write(T::MASTER_PIC_DATA_PORT, icw4);
+        self.0.write(T::SLAVE_PIC_DATA_PORT, icw4);
+
+        Pic(PortIOWrapper(self.0))
+    }
+
+    /// Send ICW4 which sets PICs to Automatic End Of Interrupt (AEOI) mode
+    /// with special fully nested mode enabled.
+    ///
+    /// Special fully nested mode preserves the relative priority of slave
+    /// interrupts on the master: a higher-priority IRQ arriving at the slave
+    /// can preempt a lower-priority slave IRQ already in service, which
+    /// normal nested mode blocks because the master only sees the cascade
+    /// line as a single in-service level.
+    pub fn send_icw4_special_fully_nested_aeoi(mut self) -> PicAEOI<T> {
+        let icw4 = ICW4Bits::ENABLE_8068_MODE
+            | ICW4Bits::AUTOMATIC_END_OF_INTERRUPT
+            | ICW4Bits::SPECIAL_FULLY_NESTED_MODE;
+        self.0.write(T::MASTER_PIC_DATA_PORT, icw4);
+        self.0.write(T::SLAVE_PIC_DATA_PORT, icw4);
+
+        PicAEOI(PortIOWrapper(self.0))
+    }
+
+    /// Send ICW4 which sets PICs to default End Of Interrupt (EOI) mode
+    /// with special fully nested mode enabled.
+    ///
+    /// Special fully nested mode preserves the relative priority of slave
+    /// interrupts on the master: a higher-priority IRQ arriving at the slave
+    /// can preempt a lower-priority slave IRQ already in service, which
+    /// normal nested mode blocks because the master only sees the cascade
+    /// line as a single in-service level.
+    pub fn send_icw4_special_fully_nested(mut self) -> Pic<T> {
+        let icw4 = ICW4Bits::ENABLE_8068_MODE | ICW4Bits::SPECIAL_FULLY_NESTED_MODE;
+        self.0.write(T::MASTER_PIC_DATA_PORT, icw4);
+        self.0.write(T::SLAVE_PIC_DATA_PORT, icw4);
+
+        Pic(PortIOWrapper(self.0))
+    }
+}
+
+/// Send the fourth Initialization Command Word (ICW) in single PIC mode.
+pub struct ICW4Single<T: PortIO>(T);
+
+impl <T: PortIO> ICW4Single<T> {
+    /// Send ICW4 which sets the PIC to Automatic End Of Interrupt (AEOI) mode.
+    ///
+    /// Note that some PC hardware doesn't support AEOI mode.
+    ///
+    /// This is the most efficient PIC mode, because you don't
+    /// send end of interrupt message to the PIC after every
+    /// interrupt.
+    pub fn send_icw4_aeoi(mut self) -> PicAEOI<T> {
+        let icw4 = ICW4Bits::ENABLE_8068_MODE | ICW4Bits::AUTOMATIC_END_OF_INTERRUPT;
+        self.0.write(T::MASTER_PIC_DATA_PORT, icw4);
+
+        PicAEOI(PortIOWrapper(self.0))
+    }
+
+    /// Send ICW4 which sets the PIC to default End Of Interrupt (EOI) mode.
+    ///
+    /// In this mode you must send a end of interrupt
+    /// message when receiving interrupt from the PIC.
+    pub fn send_icw4(mut self) -> Pic<T> {
+        let icw4 = ICW4Bits::ENABLE_8068_MODE;
+        self.0.write(T::MASTER_PIC_DATA_PORT, icw4);
+
+        Pic(PortIOWrapper(self.0))
+    }
+}
@@ -75,14 +75,6 @@
 //! * <http://www.vcfed.org/forum/archive/index.php/t-50290.html>
 //! * <https://scalibq.wordpress.com/2015/12/15/pc-compatibility-its-all-relative/>
 //!
-//! # Currently unimplemented features
-//!
-//! Read the Intel reference for more info about these features.
-//!
-//! * Specific End Of Interrupt
-//! * Interrupt priority rotation
-//! * Special fully nested mode
-//!
 //! # Why there is no option to enable PIC buffered mode?
 //!
 //! PC/AT probably doesn't require/support it, because IBM reference BIOS code
@@ -107,7 +99,7 @@ pub mod raw;
 
 pub use init::{PicInit, InterruptTriggerMode};
 
-use raw::{OCW3ReadRegisterCommand, OCW2Commands};
+use raw::{OCW3ReadRegisterCommand, OCW2Commands, OCW2IRLevel, OCW3Bits, OCW3SpecialMaskMode};
 
 pub trait PortIO {
     const MASTER_PIC_COMMAND_PORT: u16 = 0x20;
@@ -168,12 +160,133 @@ pub trait SendEOI<T: PortIO>: PortIOAvailable<T> {
     fn send_eoi_to_slave(&mut self) {
         self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW2Commands::NonSpecificEOI.bits());
     }
+
+    /// Send a specific end of interrupt for `level` to the master PIC.
+    ///
+    /// Use this instead of [`SendEOI::send_eoi_to_master`] when the PIC is
+    /// running in a priority mode where clearing the highest-priority
+    /// in-service bit, which is what a non-specific EOI does, would clear
+    /// the wrong bit.
+    fn send_specific_eoi_to_master(&mut self, level: OCW2IRLevel) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW2Commands::SpecificEOI.bits() | level.bits());
+    }
+
+    /// Send a specific end of interrupt for `level` to the slave PIC.
+    ///
+    /// When the interrupt being acknowledged came from the slave, this must
+    /// be followed by a specific (or non-specific) EOI to the master PIC for
+    /// the IR line the slave cascades on, since the master's in-service bit
+    /// for that line is separate from the slave's.
+    fn send_specific_eoi_to_slave(&mut self, level: OCW2IRLevel) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW2Commands::SpecificEOI.bits() | level.bits());
+    }
 }
 
 impl <T: PortIO> SendEOI<T> for Pic<T> {}
 impl <T: PortIO> SendEOI<T> for RegisterReadModeIRR<T, Pic<T>> {}
 impl <T: PortIO> SendEOI<T> for RegisterReadModeISR<T, Pic<T>> {}
 
+/// Methods for rotating interrupt priority.
+///
+/// By default IR0 always has the highest priority and IR7 the lowest.
+/// Rotating priority gives equal-priority devices fair, round-robin
+/// servicing instead of that fixed ordering.
+pub trait PicRotatePriority<T: PortIO>: PortIOAvailable<T> {
+    /// Send a non-specific EOI to the master PIC and rotate its priority.
+    ///
+    /// The IR line that was just serviced becomes the lowest priority.
+    /// Later EOIs sent with [`SendEOI::send_eoi_to_master`] don't rotate priority.
+    fn rotate_on_non_specific_eoi_master(&mut self) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW2Commands::RotateOnNonSpecificEOI.bits());
+    }
+
+    /// Send a non-specific EOI to the slave PIC and rotate its priority.
+    ///
+    /// The IR line that was just serviced becomes the lowest priority.
+    /// Later EOIs sent with [`SendEOI::send_eoi_to_slave`] don't rotate priority.
+    fn rotate_on_non_specific_eoi_slave(&mut self) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW2Commands::RotateOnNonSpecificEOI.bits());
+    }
+
+    /// Send a specific EOI to the master PIC for `level` and make `level` the lowest priority.
+    fn rotate_on_specific_eoi_master(&mut self, level: OCW2IRLevel) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW2Commands::RotateOnSpecificEOI.bits() | level.bits());
+    }
+
+    /// Send a specific EOI to the slave PIC for `level` and make `level` the lowest priority.
+    fn rotate_on_specific_eoi_slave(&mut self, level: OCW2IRLevel) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW2Commands::RotateOnSpecificEOI.bits() | level.bits());
+    }
+
+    /// Set priority on the master PIC so that `level` becomes the lowest priority,
+    /// making the next IR line the highest priority.
+    fn set_priority_master(&mut self, level: OCW2IRLevel) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW2Commands::SetPriority.bits() | level.bits());
+    }
+
+    /// Set priority on the slave PIC so that `level` becomes the lowest priority,
+    /// making the next IR line the highest priority.
+    fn set_priority_slave(&mut self, level: OCW2IRLevel) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW2Commands::SetPriority.bits() | level.bits());
+    }
+
+    /// Enable automatic rotation of priority on the master PIC while it's in AEOI mode.
+    fn enable_automatic_rotation_master(&mut self) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW2Commands::RotateInAEOIModeSet.bits());
+    }
+
+    /// Enable automatic rotation of priority on the slave PIC while it's in AEOI mode.
+    fn enable_automatic_rotation_slave(&mut self) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW2Commands::RotateInAEOIModeSet.bits());
+    }
+
+    /// Disable automatic rotation of priority on the master PIC while it's in AEOI mode.
+    fn disable_automatic_rotation_master(&mut self) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW2Commands::RotateInAEOIModeClear.bits());
+    }
+
+    /// Disable automatic rotation of priority on the slave PIC while it's in AEOI mode.
+    fn disable_automatic_rotation_slave(&mut self) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW2Commands::RotateInAEOIModeClear.bits());
+    }
+}
+
+impl <T: PortIO> PicRotatePriority<T> for Pic<T> {}
+impl <T: PortIO> PicRotatePriority<T> for PicAEOI<T> {}
+
+/// Poll for the highest-priority pending interrupt without using the IDT.
+///
+/// Reading the poll word acts as an interrupt acknowledge: it sets the
+/// in-service bit for the returned IR line just like a real interrupt would.
+/// This is useful for bare-metal code that wants to check for interrupts in
+/// a loop without installing real interrupt vectors.
+pub trait PicPoll<T: PortIO>: PortIOAvailable<T> {
+    /// Poll the master PIC, returning the highest-priority pending IR line, if any.
+    fn poll_master(&mut self) -> Option<OCW2IRLevel> {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW3Bits::POLL_COMMAND);
+        decode_poll_word(self.port_io_mut().read(T::MASTER_PIC_COMMAND_PORT))
+    }
+
+    /// Poll the slave PIC, returning the highest-priority pending IR line, if any.
+    fn poll_slave(&mut self) -> Option<OCW2IRLevel> {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW3Bits::POLL_COMMAND);
+        decode_poll_word(self.port_io_mut().read(T::SLAVE_PIC_COMMAND_PORT))
+    }
+}
+
+impl <T: PortIO> PicPoll<T> for Pic<T> {}
+impl <T: PortIO> PicPoll<T> for PicAEOI<T> {}
+
+fn decode_poll_word(poll_word: u8) -> Option<OCW2IRLevel> {
+    const INTERRUPT_PENDING: u8 = 0b1000_0000;
+
+    if poll_word & INTERRUPT_PENDING != 0 {
+        Some(OCW2IRLevel::from_bits(poll_word))
+    } else {
+        None
+    }
+}
+
 /// Methods for changing interrupt masks.
 ///
 /// Note that probably spurious IRQs may occur unless
@@ -203,6 +316,81 @@ impl <T: PortIO> PicMask<T> for Pic<T> {}
 impl <T: PortIO, U: PortIOAvailable<T>> PicMask<T> for RegisterReadModeIRR<T, U> {}
 impl <T: PortIO, U: PortIOAvailable<T>> PicMask<T> for RegisterReadModeISR<T, U> {}
 
+/// Methods for enabling special mask mode.
+///
+/// In special mask mode, masking a bit in the IMR (see [`PicMask`]) also
+/// inhibits that level's in-service bit from blocking lower-priority
+/// interrupts. This lets a handler mask the IR line it's currently
+/// servicing with [`PicMask::set_master_mask`]/[`PicMask::set_slave_mask`]
+/// and then re-enable interrupts, allowing lower-priority IRQs to preempt it,
+/// without the normal nested mode blocking them.
+pub trait PicSpecialMaskMode<T: PortIO>: PortIOAvailable<T> {
+    fn set_special_mask_mode_master(&mut self) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW3SpecialMaskMode::Set.bits());
+    }
+
+    fn set_special_mask_mode_slave(&mut self) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW3SpecialMaskMode::Set.bits());
+    }
+
+    fn reset_special_mask_mode_master(&mut self) {
+        self.port_io_mut().write(T::MASTER_PIC_COMMAND_PORT, OCW3SpecialMaskMode::Reset.bits());
+    }
+
+    fn reset_special_mask_mode_slave(&mut self) {
+        self.port_io_mut().write(T::SLAVE_PIC_COMMAND_PORT, OCW3SpecialMaskMode::Reset.bits());
+    }
+}
+
+impl <T: PortIO> PicSpecialMaskMode<T> for PicAEOI<T> {}
+impl <T: PortIO> PicSpecialMaskMode<T> for Pic<T> {}
+impl <T: PortIO, U: PortIOAvailable<T>> PicSpecialMaskMode<T> for RegisterReadModeIRR<T, U> {}
+impl <T: PortIO, U: PortIOAvailable<T>> PicSpecialMaskMode<T> for RegisterReadModeISR<T, U> {}
+
+/// An IR line which may deliver a spurious interrupt.
+///
+/// <https://wiki.osdev.org/8259_PIC#Spurious_IRQs>
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpuriousIRQSource {
+    /// IRQ7 on the master PIC.
+    MasterIRQ7,
+    /// IRQ15 on the slave PIC.
+    SlaveIRQ15,
+}
+
+impl <T: PortIO> Pic<T> {
+    /// Check whether a fired `source` interrupt was spurious, sending
+    /// the correct (possibly no) end of interrupt for a spurious one.
+    ///
+    /// For a genuine interrupt this method sends no EOI.
+    ///
+    /// A spurious master IRQ7 needs no EOI at all. A spurious slave IRQ15
+    /// still needs a non-specific EOI sent to the master PIC, because the
+    /// master has no way of knowing that the slave's interrupt was spurious.
+    /// This method sends that EOI itself, so callers can't forget it or
+    /// double-ack the interrupt.
+    ///
+    /// Returns `true` if the interrupt was spurious.
+    pub fn check_spurious_irq(&mut self, source: SpuriousIRQSource) -> bool {
+        const IN_SERVICE_BIT_7: u8 = 0b1000_0000;
+
+        let command_port = match source {
+            SpuriousIRQSource::MasterIRQ7 => T::MASTER_PIC_COMMAND_PORT,
+            SpuriousIRQSource::SlaveIRQ15 => T::SLAVE_PIC_COMMAND_PORT,
+        };
+
+        self.port_io_mut().write(command_port, OCW3ReadRegisterCommand::InService.bits());
+        let isr = self.port_io_mut().read(command_port);
+        let spurious = isr & IN_SERVICE_BIT_7 == 0;
+
+        if spurious && source == SpuriousIRQSource::SlaveIRQ15 {
+            self.send_eoi_to_master();
+        }
+
+        spurious
+    }
+}
+
 use core::marker::PhantomData;
 
 /// Read Interrupt Request Register (IRR).
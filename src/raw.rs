@@ -73,6 +73,20 @@ impl OCW2IRLevel {
     pub const fn bits(self) -> u8 {
         self as u8
     }
+
+    /// Create `OCW2IRLevel` from the lowest 3 bits of `bits`.
+    pub const fn from_bits(bits: u8) -> Self {
+        match bits & 0b0000_0111 {
+            0 => OCW2IRLevel::Zero,
+            1 => OCW2IRLevel::One,
+            2 => OCW2IRLevel::Two,
+            3 => OCW2IRLevel::Three,
+            4 => OCW2IRLevel::Four,
+            5 => OCW2IRLevel::Five,
+            6 => OCW2IRLevel::Six,
+            _ => OCW2IRLevel::Seven,
+        }
+    }
 }
 
 #[derive(Debug)]